@@ -0,0 +1,113 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Per-language rules for recognizing type names in source text: a set of
+/// token regexes (each with exactly one capturing group holding the type
+/// name) plus prefixes that mark a line as skippable (imports, comments).
+pub struct LanguageProfile {
+    pub name: &'static str,
+    pub token_regexes: Vec<Regex>,
+    pub skip_prefixes: &'static [&'static str],
+}
+
+impl LanguageProfile {
+    pub(crate) fn is_skippable(&self, line: &str) -> bool {
+        line.is_empty() || self.skip_prefixes.iter().any(|p| line.starts_with(p))
+    }
+
+    /// Extracts potential type names from a single preprocessed line,
+    /// inserting each hit into `types`.
+    pub fn extract_line(&self, line: &str, types: &mut std::collections::BTreeSet<String>) {
+        if self.is_skippable(line) {
+            return;
+        }
+        for token in line.split_whitespace() {
+            for re in &self.token_regexes {
+                if let Some(caps) = re.captures(token) {
+                    if let Some(type_name) = caps.get(1) {
+                        types.insert(type_name.as_str().to_string());
+                    }
+                    break;
+                }
+            }
+        }
+    }
+}
+
+fn swift_profile() -> LanguageProfile {
+    LanguageProfile {
+        name: "swift",
+        // Note: lines are preprocessed to strip punctuation (including
+        // brackets) before tokenizing, so `[TypeName]` already arrives as
+        // the bare token `TypeName` and only needs the simple regex below.
+        token_regexes: vec![Regex::new(r"^([A-Z][A-Za-z0-9]+)$").unwrap()],
+        skip_prefixes: &["import ", "//"],
+    }
+}
+
+fn kotlin_profile() -> LanguageProfile {
+    LanguageProfile {
+        name: "kotlin",
+        token_regexes: vec![Regex::new(r"^([A-Z][A-Za-z0-9]+)$").unwrap()],
+        skip_prefixes: &["import ", "package ", "//"],
+    }
+}
+
+fn typescript_profile() -> LanguageProfile {
+    LanguageProfile {
+        name: "typescript",
+        // Note: no "export " skip prefix — that would discard the most
+        // common TS type-declaration forms (`export class Foo`, `export
+        // interface Bar`, `export type Baz`) along with their imports.
+        token_regexes: vec![Regex::new(r"^([A-Z][A-Za-z0-9]+)$").unwrap()],
+        skip_prefixes: &["import ", "//"],
+    }
+}
+
+fn rust_profile() -> LanguageProfile {
+    LanguageProfile {
+        name: "rust",
+        token_regexes: vec![Regex::new(r"^([A-Z][A-Za-z0-9]+)$").unwrap()],
+        skip_prefixes: &["use ", "//"],
+    }
+}
+
+/// Built-in language profiles keyed by file extension (without the dot).
+static PROFILES: Lazy<HashMap<&'static str, LanguageProfile>> = Lazy::new(|| {
+    let mut profiles = HashMap::new();
+    profiles.insert("swift", swift_profile());
+    profiles.insert("kt", kotlin_profile());
+    profiles.insert("kts", kotlin_profile());
+    profiles.insert("ts", typescript_profile());
+    profiles.insert("tsx", typescript_profile());
+    profiles.insert("rs", rust_profile());
+    profiles
+});
+
+/// Looks up the built-in profile registered for a file extension (without
+/// the leading dot), e.g. `"swift"` or `"rs"`.
+pub fn profile_for_extension(extension: &str) -> Option<&'static LanguageProfile> {
+    PROFILES.get(extension)
+}
+
+/// Looks up a built-in profile by name (e.g. `"swift"`, `"rust"`),
+/// independent of any particular file extension.
+pub fn profile_by_name(name: &str) -> Option<&'static LanguageProfile> {
+    PROFILES.values().find(|p| p.name == name)
+}
+
+/// Returns every file extension registered for the profile with the given
+/// name, e.g. `"kotlin"` -> `["kt", "kts"]`.
+pub fn extensions_for_name(name: &str) -> Vec<&'static str> {
+    PROFILES
+        .iter()
+        .filter(|(_, profile)| profile.name == name)
+        .map(|(ext, _)| *ext)
+        .collect()
+}
+
+/// The profile used when a file's extension isn't registered.
+pub fn default_profile() -> &'static LanguageProfile {
+    profile_for_extension("swift").expect("swift profile is always registered")
+}
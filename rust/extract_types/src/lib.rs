@@ -0,0 +1,121 @@
+pub mod language;
+
+use language::LanguageProfile;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// Reads a source file and returns the sorted, unique set of potential
+/// type names found in it, using the language profile registered for its
+/// extension (falling back to the Swift profile for unrecognized ones).
+pub fn extract_types_from_file<P: AsRef<Path>>(swift_file: P) -> Result<BTreeSet<String>, Box<dyn std::error::Error + Send + Sync>> {
+    let path = swift_file.as_ref();
+    let profile = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .and_then(language::profile_for_extension)
+        .unwrap_or_else(language::default_profile);
+    extract_types_with_profile(path, profile)
+}
+
+/// Reads a source file and returns the sorted, unique set of potential
+/// type names found in it, using the given language profile explicitly.
+pub fn extract_types_with_profile<P: AsRef<Path>>(
+    file: P,
+    profile: &LanguageProfile,
+) -> Result<BTreeSet<String>, Box<dyn std::error::Error + Send + Sync>> {
+    let file = File::open(file)?;
+    let reader = BufReader::new(file);
+
+    let mut types = BTreeSet::new();
+
+    for line in reader.lines() {
+        let mut line = line?;
+        // Check skip prefixes on the raw line: preprocessing below turns
+        // the `//` of a comment into whitespace.
+        if profile.is_skippable(line.trim()) {
+            continue;
+        }
+        // Preprocessing: replace non-alphanumeric characters with whitespace.
+        line = line.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' }).collect();
+        let line = line.trim();
+        profile.extract_line(line, &mut types);
+    }
+
+    Ok(types)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::Builder;
+
+    #[test]
+    fn test_extract_types_returns_empty_for_file_with_no_capitalized_words() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut swift_file = Builder::new().suffix(".swift").tempfile()?;
+        writeln!(swift_file, "import foundation\nlet x = 5")?;
+        let types = extract_types_from_file(swift_file.path())?;
+        assert!(types.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_types_extracts_capitalized_words() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut swift_file = Builder::new().suffix(".swift").tempfile()?;
+        writeln!(
+            swift_file,
+            "import Foundation
+class MyClass {{}}
+struct MyStruct {{}}
+enum MyEnum {{}}"
+        )?;
+        let types = extract_types_from_file(swift_file.path())?;
+        let expected: BTreeSet<String> = ["MyClass", "MyEnum", "MyStruct"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(types, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_types_extracts_type_names_from_bracket_notation() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut swift_file = Builder::new().suffix(".swift").tempfile()?;
+        writeln!(swift_file, "import UIKit\nlet array: [CustomType] = []")?;
+        let types = extract_types_from_file(swift_file.path())?;
+        let expected: BTreeSet<String> = ["CustomType"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(types, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_types_dispatches_by_extension_for_rust_files() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut rust_file = Builder::new().suffix(".rs").tempfile()?;
+        writeln!(rust_file, "use std::collections::HashMap;\nstruct MyStruct;")?;
+        let types = extract_types_from_file(rust_file.path())?;
+        let expected: BTreeSet<String> = ["MyStruct"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(types, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_types_finds_exported_typescript_declarations() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut ts_file = Builder::new().suffix(".ts").tempfile()?;
+        writeln!(
+            ts_file,
+            "import {{ Something }} from './something';
+export class Widget {{}}
+export interface Gadget {{}}
+export type Sprocket = {{ id: number }};"
+        )?;
+        let types = extract_types_from_file(ts_file.path())?;
+        let expected: BTreeSet<String> = ["Gadget", "Sprocket", "Widget"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(types, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use extract_types::{extract_types_from_file, language};
+use ignore::overrides::OverrideBuilder;
+use ignore::WalkBuilder;
+use rayon::prelude::*;
+use std::collections::BTreeSet;
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Directories that should never be scanned for source files, even if
+/// they aren't covered by a project's own .gitignore.
+const EXCLUDED_DIRS: &[&str] = &[".build", "Pods", "DerivedData"];
+
+/// Pins the global rayon thread pool to `threads` worker threads. Must be
+/// called at most once per process, before any parallel work is kicked off.
+fn set_number_of_threads(threads: usize) {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .expect("Failed to configure rayon thread pool");
+}
+
+/// Collects every source file beneath `root` whose extension is covered by
+/// `allowed_extensions` (or by any registered language profile, when
+/// `allowed_extensions` is `None`), honoring .gitignore/.ignore and the
+/// excludes above — the same ignore-crate traversal get_search_roots uses.
+fn collect_source_files<P: AsRef<Path>>(
+    root: P,
+    allowed_extensions: Option<&[&str]>,
+) -> Result<Vec<PathBuf>, Box<dyn std::error::Error + Send + Sync>> {
+    let root = root.as_ref();
+    let mut overrides = OverrideBuilder::new(root);
+    for dir in EXCLUDED_DIRS {
+        overrides.add(&format!("!{}", dir))?;
+    }
+    let overrides = overrides.build()?;
+
+    let files = WalkBuilder::new(root)
+        .overrides(overrides)
+        .build()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_some_and(|ft| ft.is_file()))
+        .filter(|e| {
+            let extension = e.path().extension().and_then(|e| e.to_str());
+            match (extension, allowed_extensions) {
+                (Some(ext), Some(allowed)) => allowed.contains(&ext),
+                (Some(ext), None) => language::profile_for_extension(ext).is_some(),
+                (None, _) => false,
+            }
+        })
+        .map(|e| e.path().to_path_buf())
+        .collect();
+    Ok(files)
+}
+
+/// Extracts the union of referenced type names from every source file in
+/// `files`, fanning the work out across the rayon thread pool and merging
+/// each file's local set back together.
+fn extract_types_from_files(files: &[PathBuf]) -> Result<BTreeSet<String>, Box<dyn std::error::Error + Send + Sync>> {
+    files
+        .par_iter()
+        .map(|path| extract_types_from_file(path))
+        .try_reduce(BTreeSet::new, |mut a, b| {
+            a.extend(b);
+            Ok(a)
+        })
+}
+
+/// Walks `root` once, extracting types from every source file it
+/// encounters whose extension is covered by `allowed_extensions` (or by
+/// any registered language profile, when `allowed_extensions` is `None`),
+/// and returns the union of all referenced type names across the whole
+/// project.
+fn extract_types_from_project<P: AsRef<Path>>(
+    root: P,
+    allowed_extensions: Option<&[&str]>,
+) -> Result<BTreeSet<String>, Box<dyn std::error::Error + Send + Sync>> {
+    let files = collect_source_files(root, allowed_extensions)?;
+    extract_types_from_files(&files)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let mut root = None;
+    let mut threads = num_cpus::get();
+    let mut selected_language = None;
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        if arg == "--threads" {
+            let value = iter.next().unwrap_or_else(|| {
+                eprintln!("Usage: {} [--threads N] [--language NAME] [project_root]", args[0]);
+                std::process::exit(1);
+            });
+            threads = value.parse().unwrap_or_else(|_| {
+                eprintln!("Invalid value for --threads: {}", value);
+                std::process::exit(1);
+            });
+        } else if arg == "--language" {
+            let value = iter.next().unwrap_or_else(|| {
+                eprintln!("Usage: {} [--threads N] [--language NAME] [project_root]", args[0]);
+                std::process::exit(1);
+            });
+            if language::profile_by_name(value).is_none() {
+                eprintln!("Unknown language: {}", value);
+                std::process::exit(1);
+            }
+            selected_language = Some(value.clone());
+        } else if root.is_none() {
+            root = Some(arg.clone());
+        }
+    }
+
+    let allowed_extensions = selected_language.as_deref().map(language::extensions_for_name);
+
+    // When no root is given on the command line, discover one from the
+    // enclosing git repository (or the nearest Package.swift).
+    let root = root.unwrap_or_else(|| repo_context::discover_root().display().to_string());
+
+    set_number_of_threads(threads);
+
+    let allowed_extensions = allowed_extensions.as_deref();
+    match extract_types_from_project(&root, allowed_extensions) {
+        Ok(types) => {
+            for type_name in &types {
+                println!("{}", type_name);
+            }
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_extract_types_from_project_dedupes_across_files() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let dir = tempdir()?;
+        fs::write(
+            dir.path().join("A.swift"),
+            "import Foundation\nclass MyClass {}\nstruct MyStruct {}",
+        )?;
+        fs::write(
+            dir.path().join("B.swift"),
+            "let array: [MyStruct] = []\nenum MyEnum {}",
+        )?;
+
+        let types = extract_types_from_project(dir.path(), None)?;
+        let expected: BTreeSet<String> = ["MyClass", "MyEnum", "MyStruct"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(types, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_types_from_project_skips_build_dir() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let dir = tempdir()?;
+        fs::create_dir(dir.path().join(".build"))?;
+        fs::write(dir.path().join(".build").join("Generated.swift"), "class Ignored {}")?;
+        fs::write(dir.path().join("Main.swift"), "class Kept {}")?;
+
+        let types = extract_types_from_project(dir.path(), None)?;
+        let expected: BTreeSet<String> = ["Kept"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(types, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_types_from_project_dispatches_across_languages() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let dir = tempdir()?;
+        fs::write(dir.path().join("Main.swift"), "class SwiftType {}")?;
+        fs::write(dir.path().join("lib.rs"), "struct RustType;")?;
+
+        let types = extract_types_from_project(dir.path(), None)?;
+        let expected: BTreeSet<String> = ["RustType", "SwiftType"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(types, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_types_from_project_restricts_to_selected_language() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let dir = tempdir()?;
+        fs::write(dir.path().join("Main.swift"), "class SwiftType {}")?;
+        fs::write(dir.path().join("lib.rs"), "struct RustType;")?;
+
+        let rust_extensions = language::extensions_for_name("rust");
+        let types = extract_types_from_project(dir.path(), Some(&rust_extensions))?;
+        let expected: BTreeSet<String> = ["RustType"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(types, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_types_from_files_merges_parallel_results() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let dir = tempdir()?;
+        let a = dir.path().join("A.swift");
+        let b = dir.path().join("B.swift");
+        fs::write(&a, "class MyClass {}")?;
+        fs::write(&b, "struct MyStruct {}")?;
+
+        let types = extract_types_from_files(&[a, b])?;
+        let expected: BTreeSet<String> = ["MyClass", "MyStruct"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(types, expected);
+        Ok(())
+    }
+}
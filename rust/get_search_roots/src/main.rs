@@ -1,16 +1,53 @@
+use ignore::overrides::OverrideBuilder;
+use ignore::WalkBuilder;
+use std::collections::BTreeSet;
 use std::env;
 use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
-use std::collections::BTreeSet;
+
+/// Directories that should never be treated as (or searched for) package
+/// roots, even if they aren't covered by a project's own .gitignore.
+const EXCLUDED_DIRS: &[&str] = &[".build", "Pods", "DerivedData"];
+
+/// Walks `root_path`, honoring .gitignore/.ignore and the excludes above,
+/// and returns every directory that contains a Package.swift.
+fn find_package_roots(root_path: &Path) -> Result<BTreeSet<PathBuf>, Box<dyn std::error::Error>> {
+    let mut overrides = OverrideBuilder::new(root_path);
+    for dir in EXCLUDED_DIRS {
+        overrides.add(&format!("!{}", dir))?;
+    }
+    let overrides = overrides.build()?;
+
+    let mut found_dirs = BTreeSet::new();
+    for entry in WalkBuilder::new(root_path)
+        .overrides(overrides)
+        .build()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_some_and(|ft| ft.is_file()))
+    {
+        if entry.file_name() == "Package.swift" {
+            if let Some(dir) = entry.path().parent() {
+                found_dirs.insert(dir.to_path_buf());
+            }
+        }
+    }
+    Ok(found_dirs)
+}
 
 fn main() {
-    // Expect exactly one argument: the root directory.
+    // The root directory is optional: when omitted, it's discovered from
+    // the enclosing git repository (or the nearest Package.swift).
     let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        eprintln!("Usage: {} <git_root_or_package_root>", args[0]);
+    if args.len() > 2 {
+        eprintln!("Usage: {} [git_root_or_package_root]", args[0]);
         std::process::exit(1);
     }
-    let root = &args[1];
+    let discovered;
+    let root: &str = if let Some(arg) = args.get(1) {
+        arg
+    } else {
+        discovered = repo_context::discover_root().display().to_string();
+        &discovered
+    };
     let root_path = Path::new(root);
 
     // If the root itself is a Swift package (contains Package.swift), print it and exit.
@@ -19,9 +56,9 @@ fn main() {
         return;
     }
 
-    // Otherwise, print the root if it is not a ".build" directory.
+    // Otherwise, print the root if it is not an excluded directory.
     if let Some(basename) = root_path.file_name().and_then(|s| s.to_str()) {
-        if basename != ".build" {
+        if !EXCLUDED_DIRS.contains(&basename) {
             println!("{}", root);
         }
     } else {
@@ -29,23 +66,15 @@ fn main() {
         println!("{}", root);
     }
 
-    // Now, find any subdirectories that contain Package.swift but exclude those inside .build folders.
-    let mut found_dirs = BTreeSet::new();
-    for entry in WalkDir::new(root_path)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.file_type().is_file())
-    {
-        if entry.file_name() == "Package.swift" {
-            let path_str = entry.path().to_string_lossy();
-            if path_str.contains("/.build/") {
-                continue;
-            }
-            if let Some(dir) = entry.path().parent() {
-                found_dirs.insert(dir.to_path_buf());
-            }
+    // Now, find any subdirectories that contain Package.swift, respecting
+    // .gitignore/.ignore and the excludes above.
+    let found_dirs = match find_package_roots(root_path) {
+        Ok(dirs) => dirs,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
         }
-    }
+    };
 
     // Print the unique directories (BTreeSet ensures sorted order).
     for dir in found_dirs {
@@ -0,0 +1,46 @@
+use git2::Repository;
+use once_cell::sync::OnceCell;
+use std::path::PathBuf;
+
+/// The working directory of the git repository enclosing the current
+/// directory, discovered and cached once so repeated lookups don't
+/// re-open the repository from disk. `git2::Repository` is `Send` but not
+/// `Sync`, so it can't live in a `static` directly; the `workdir` path is
+/// all callers need, so that's what gets cached.
+static GIT_ROOT: OnceCell<Option<PathBuf>> = OnceCell::new();
+
+fn discover_git_root() -> Option<PathBuf> {
+    Repository::discover(".")
+        .ok()
+        .and_then(|r| r.workdir().map(|p| p.to_path_buf()))
+}
+
+/// Returns the working directory of the enclosing git repository, if the
+/// current directory is inside one.
+pub fn git_root() -> Option<PathBuf> {
+    GIT_ROOT.get_or_init(discover_git_root).clone()
+}
+
+/// Walks upward from the current directory looking for the nearest
+/// Package.swift, returning its containing directory.
+fn nearest_package_root() -> Option<PathBuf> {
+    let mut dir = std::env::current_dir().ok()?;
+    loop {
+        if dir.join("Package.swift").is_file() {
+            return Some(dir);
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+/// Discovers a sensible root to scan from when the caller didn't supply
+/// one: the enclosing git root, falling back to the nearest ancestor
+/// directory containing a Package.swift, falling back to the current
+/// directory.
+pub fn discover_root() -> PathBuf {
+    git_root()
+        .or_else(nearest_package_root)
+        .unwrap_or_else(|| std::env::current_dir().expect("failed to get current directory"))
+}